@@ -0,0 +1,70 @@
+use anyhow::Result;
+use crossbeam_channel::Sender;
+use notify::{
+    PollWatcher,
+    RecommendedWatcher,
+    Watcher,
+};
+use std::time::Duration;
+
+/// Which `notify` backend to watch the filesystem with.
+///
+/// `Recommended` picks whatever event-based backend `notify` considers best
+/// for the current platform (inotify, FSEvents, ...). Those don't fire on
+/// NFS, overlayfs, or many container bind-mounts, so `Poll` is available as
+/// a fallback that stats the path on an interval instead.
+pub enum WatcherBackend {
+    Recommended,
+    Poll { interval: Duration },
+}
+
+impl std::str::FromStr for WatcherBackend {
+    type Err = anyhow::Error;
+
+    /// Parses either `"recommended"` or `"poll:<milliseconds>"`, e.g.
+    /// `"poll:500"` for a 500ms poll interval.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "recommended" {
+            return Ok(WatcherBackend::Recommended);
+        }
+        if let Some(ms) = s.strip_prefix("poll:") {
+            return ms
+                .parse()
+                .map(|ms| WatcherBackend::Poll {
+                    interval: Duration::from_millis(ms),
+                })
+                .map_err(|_| {
+                    tracing::error!("Cannot parse string: {}", s);
+                    anyhow::anyhow!("Cannot parse string")
+                });
+        }
+        tracing::error!("Cannot parse string: {}", s);
+        Err(anyhow::anyhow!("Cannot parse string"))
+    }
+}
+
+impl WatcherBackend {
+    /// Builds the `notify` watcher this backend describes.
+    pub(super) fn build(
+        &self,
+        tx: Sender<notify::Result<notify::Event>>,
+    ) -> Result<Box<dyn Watcher + Send>> {
+        match self {
+            WatcherBackend::Recommended => {
+                let watcher = RecommendedWatcher::new(tx, notify::Config::default())
+                    .map_err(|e| {
+                        anyhow::anyhow!("Cannot create watcher: {:?}", e)
+                    })?;
+                Ok(Box::new(watcher))
+            }
+            WatcherBackend::Poll { interval } => {
+                let config =
+                    notify::Config::default().with_poll_interval(*interval);
+                let watcher = PollWatcher::new(tx, config).map_err(|e| {
+                    anyhow::anyhow!("Cannot create watcher: {:?}", e)
+                })?;
+                Ok(Box::new(watcher))
+            }
+        }
+    }
+}
@@ -2,21 +2,183 @@
 /// without stop/starting the service.
 /// Parameters and internals are pretty opinionated because I was
 /// using this for a pet project.
+mod backend;
 mod recursive;
 
 use anyhow::Result;
-use crossbeam_channel::unbounded;
-use notify::{
-    RecommendedWatcher,
-    Watcher,
+use arc_swap::ArcSwap;
+use backend::WatcherBackend;
+use crossbeam_channel::{
+    select,
+    unbounded,
 };
+use globset::{
+    Glob,
+    GlobSet,
+    GlobSetBuilder,
+};
+use notify::Watcher;
 use recursive::RecursiveMode;
+use signal_hook::consts::SIGHUP;
+use signal_hook::iterator::Signals;
 use std::fmt::Debug;
 use std::str::FromStr;
 use std::sync::{
     Arc,
     Mutex,
 };
+use std::time::Duration;
+
+/// How long to wait for the event stream to go quiet before reloading.
+///
+/// A single editor save can emit a create+modify+close burst of events;
+/// without debouncing that means a reload (and a fresh parse) per event.
+/// Mirrors rust-analyzer's `WATCHER_DELAY`.
+const DEBOUNCE_WINDOW: Duration = Duration::from_millis(250);
+
+/// Returns whether a notify event is one we care about reloading on.
+fn is_reload_event(kind: &notify::EventKind) -> bool {
+    // Modify: DataChange::Any gets triggered everytime you open
+    // the config file and perform empty save. It was kind of
+    // annoying so I had to use AccessKind:Close so that I can
+    // track if someone opened and closed the config file.
+    // However it does not track the changes the way Modify does
+    // so I go back to the modify.
+    // If it causes any undefined behaviours, consider changing
+    // back this to Accesskind again.
+    // Full code should be something like this:
+    // ```rust
+    // if e.kind == notify::EventKind::Access(AccessKind::Close(
+    //     AccessMode::Write,
+    // )) {...}
+    // ```
+    kind.is_modify() || kind.is_create() || kind.is_remove()
+}
+
+/// Returns whether an event touches a path matching one of the configured
+/// glob patterns. An empty `globset` (no patterns configured) matches
+/// everything, so watching plain paths without patterns keeps working.
+fn matches_patterns(globset: &GlobSet, event: &notify::Event) -> bool {
+    globset.is_empty() ||
+        event.paths.iter().any(|path| globset.is_match(path))
+}
+
+/// Builds the watcher for `backend`, registers every path in `paths`, and
+/// returns it alongside the channel `notify` delivers events on.
+///
+/// This is called exactly once per `watch_changes*` call so the watcher and
+/// its channel live for the whole life of the watching thread, instead of
+/// being torn down and rebuilt on every single event.
+fn build_watcher(
+    backend: &WatcherBackend,
+    mode: notify::RecursiveMode,
+    paths: &[String],
+) -> Result<(
+    Box<dyn Watcher + Send>,
+    crossbeam_channel::Receiver<notify::Result<notify::Event>>,
+)> {
+    let (tx, rx) = unbounded();
+    let mut watcher = backend.build(tx)?;
+    for path in paths {
+        watcher
+            .watch(std::path::Path::new(path), mode)
+            .map_err(|e| {
+                anyhow::anyhow!("Cannot listen filesystem: {:?}", e)
+            })?;
+    }
+    Ok((watcher, rx))
+}
+
+/// Keeps draining events from `rx` until the stream has been quiet for
+/// `DEBOUNCE_WINDOW`, coalescing a burst (e.g. an editor's
+/// create+modify+close save sequence) into a single reload.
+///
+/// Returns `false` if the channel disconnected in the meantime, meaning the
+/// watcher was dropped and the caller should stop watching.
+fn drain_burst(
+    rx: &crossbeam_channel::Receiver<notify::Result<notify::Event>>,
+) -> bool {
+    loop {
+        match rx.recv_timeout(DEBOUNCE_WINDOW) {
+            Ok(Ok(e)) => {
+                tracing::trace!("Captured event: {:#?}", e);
+                continue;
+            }
+            Ok(Err(e)) => {
+                tracing::error!("Event error: {:?}", e);
+                continue;
+            }
+            Err(crossbeam_channel::RecvTimeoutError::Timeout) => return true,
+            Err(crossbeam_channel::RecvTimeoutError::Disconnected) => {
+                return false
+            }
+        }
+    }
+}
+
+/// Blocks until a reload-worthy, pattern-matching event arrives, then calls
+/// [`drain_burst`] to coalesce the rest of the burst.
+///
+/// `on_watch_error` is called for watcher errors seen while waiting for the
+/// first event (e.g. a single bad event) so callers that expose a
+/// [`ReloadEvent`] channel can surface them; errors that arrive while
+/// [`drain_burst`] runs are only logged. Returns `false` if the channel
+/// disconnected, meaning the caller should stop watching.
+fn wait_for_reload<F>(
+    rx: &crossbeam_channel::Receiver<notify::Result<notify::Event>>,
+    globset: &GlobSet,
+    mut on_watch_error: F,
+) -> bool
+where
+    F: FnMut(anyhow::Error),
+{
+    loop {
+        match rx.recv() {
+            Ok(Ok(e)) => {
+                tracing::trace!("Captured event: {:#?}", e);
+                if !is_reload_event(&e.kind) ||
+                    !matches_patterns(globset, &e)
+                {
+                    continue;
+                }
+                break;
+            }
+            Ok(Err(e)) => {
+                tracing::error!("Event error: {:?}", e);
+                on_watch_error(anyhow::anyhow!("Event error: {:?}", e));
+                continue;
+            }
+            Err(e) => {
+                tracing::error!(
+                    "Receiving events from watcher error: {:?}",
+                    e
+                );
+                on_watch_error(anyhow::anyhow!(
+                    "Receiving events from watcher error: {:?}",
+                    e
+                ));
+                return false;
+            }
+        }
+    }
+    drain_burst(rx)
+}
+
+/// Outcome of a single reload attempt, reported on the channel
+/// [`watch_changes`] returns.
+///
+/// `f()` failing to parse a freshly-edited file is not fatal: the last
+/// known-good config is kept and served. This enum is what lets a caller
+/// observe that instead of digging through `tracing` logs.
+#[derive(Debug)]
+pub enum ReloadEvent {
+    /// The config was reloaded and swapped in.
+    Reloaded,
+    /// `f()` returned an error; the previous config is still in effect.
+    Rejected(anyhow::Error),
+    /// The watcher itself errored (e.g. the channel disconnected).
+    WatchError(anyhow::Error),
+}
 
 /// A single function that handles the automatic config reload.
 ///
@@ -43,13 +205,188 @@ use std::sync::{
 /// let cfg = Arc::new(Mutex::new(Config::load()?));
 /// let clone = Arc::clone(&cfg);
 ///
-/// watch_changes::<Config>(
+/// // `paths` are concrete files or directories registered directly with
+/// // `notify` — not glob expressions. `patterns` filters which of the
+/// // events `notify` reports under those paths actually trigger a reload.
+/// let reload_events = watch_changes::<Config>(
 ///   cfg,
 ///   "recursive".to_string(),
-///   "/my/config/file/path/config.yaml".to_string()
+///   "recommended".to_string(),
+///   vec!["/my/config/dir".to_string()],
+///   vec!["*.yaml".to_string()],
 ///   Config::load,
-/// );
+/// )?;
+///
+/// // Each reload attempt is reported on the returned channel, so the
+/// // caller can surface status or bump metrics without scraping logs.
+/// for event in reload_events {
+///   tracing::info!("Reload event: {:?}", event);
+/// }
 pub fn watch_changes<C>(
+    cfg: Arc<Mutex<C>>,
+    mode: String,
+    backend: String,
+    paths: Vec<String>,
+    patterns: Vec<String>,
+    f: fn() -> Result<C>,
+) -> Result<crossbeam_channel::Receiver<ReloadEvent>>
+where
+    C: Debug + Send + 'static,
+{
+    let mode = RecursiveMode::from_str(&mode)?.convert();
+    let backend = WatcherBackend::from_str(&backend)?;
+
+    let mut builder = GlobSetBuilder::new();
+    for pattern in &patterns {
+        builder.add(Glob::new(pattern)?);
+    }
+    let globset = builder.build()?;
+
+    let (watcher, rx) = build_watcher(&backend, mode, &paths)?;
+    // Bounded so a caller that never drains reload events can't leak
+    // memory; once 16 outcomes are queued, newer ones are dropped.
+    let (event_tx, event_rx) = crossbeam_channel::bounded(16);
+
+    std::thread::spawn(move || {
+        // Keep the watcher alive for the life of the thread; dropping it
+        // cancels the filesystem subscription.
+        let _watcher = watcher;
+        loop {
+            let still_watching = wait_for_reload(&rx, &globset, |e| {
+                if event_tx.try_send(ReloadEvent::WatchError(e)).is_err() {
+                    tracing::trace!(
+                        "Reload event channel full or closed, dropping event"
+                    );
+                }
+            });
+            if !still_watching {
+                return;
+            }
+
+            match f() {
+                Ok(new_config) => {
+                    tracing::trace!(
+                        "New config: {:?} - Old config: {:?}",
+                        &new_config,
+                        cfg,
+                    );
+                    *cfg.lock().unwrap() = new_config;
+                    if event_tx.try_send(ReloadEvent::Reloaded).is_err() {
+                        tracing::trace!(
+                            "Reload event channel full or closed, dropping event"
+                        );
+                    }
+                }
+                Err(e) => {
+                    tracing::error!("Cannot reload config: {:?}", e);
+                    if event_tx.try_send(ReloadEvent::Rejected(e)).is_err() {
+                        tracing::trace!(
+                            "Reload event channel full or closed, dropping event"
+                        );
+                    }
+                }
+            }
+        }
+    });
+    Ok(event_rx)
+}
+
+/// Like [`watch_changes`] but backed by `ArcSwap` instead of `Mutex`.
+///
+/// It watches a single `path` with the recommended backend; it takes no
+/// glob patterns and returns no [`ReloadEvent`] channel.
+///
+/// `Arc<Mutex<C>>` forces every reader to take a lock, which contends with
+/// the watcher thread every time it reloads. `Arc<ArcSwap<C>>` lets hot-path
+/// readers do a cheap atomic pointer load via `cfg.load()` while the watcher
+/// publishes a freshly-parsed config with a single `cfg.store(Arc::new(...))`.
+/// Readers never observe a half-updated struct, and there's no lock to
+/// contend on.
+///
+/// Assume you have a config struct that is called Config
+/// ```no_run
+/// #[derive(serde::Deserialize)]
+/// struct Config {
+///    key: String,
+/// }
+/// // Now you have to implement your own loading config logic.
+/// impl Config {
+///   fn load() -> anyhow::Result<Config> {
+///     let f = BufReader::new(File::open("./config.yaml")?);
+///     serde_yaml::from_reader(f).map_err(|e| {
+///       anyhow::anyhow!("Cannot parse the config file: {}",e.to_string())
+///     })
+///   }
+/// }
+///
+/// // Create a config
+/// let cfg = Arc::new(ArcSwap::from_pointee(Config::load()?));
+/// let clone = Arc::clone(&cfg);
+///
+/// watch_changes_swap::<Config>(
+///   cfg,
+///   "recursive".to_string(),
+///   "/my/config/file/path/config.yaml".to_string(),
+///   Config::load,
+/// );
+pub fn watch_changes_swap<C>(
+    cfg: Arc<ArcSwap<C>>,
+    mode: String,
+    path: String,
+    f: fn() -> Result<C>,
+) -> Result<()>
+where
+    C: Debug + Send + Sync + 'static,
+{
+    let mode = RecursiveMode::from_str(&mode)?.convert();
+    let no_patterns = GlobSetBuilder::new().build()?;
+    let (watcher, rx) =
+        build_watcher(&WatcherBackend::Recommended, mode, &[path])?;
+
+    std::thread::spawn(move || {
+        // Keep the watcher alive for the life of the thread; dropping it
+        // cancels the filesystem subscription.
+        let _watcher = watcher;
+        loop {
+            if !wait_for_reload(&rx, &no_patterns, |_| {}) {
+                return;
+            }
+
+            match f() {
+                Ok(new_config) => {
+                    tracing::trace!(
+                        "New config: {:?} - Old config: {:?}",
+                        &new_config,
+                        cfg.load(),
+                    );
+                    cfg.store(Arc::new(new_config));
+                }
+                Err(e) => tracing::error!("Cannot reload config: {:?}", e),
+            }
+        }
+    });
+    Ok(())
+}
+
+/// What woke up [`watch_changes_with_signal`]'s watcher thread.
+enum Trigger {
+    /// A filesystem event arrived; the bool is whether it's reload-worthy.
+    Fs(bool),
+    /// A `SIGHUP` was received.
+    Sig,
+}
+
+/// Like [`watch_changes`] but also reloads on `SIGHUP`.
+///
+/// It watches a single `path` with the recommended backend; it takes no
+/// glob patterns and returns no [`ReloadEvent`] channel.
+///
+/// File events are not reliable everywhere: atomic-rename editors and
+/// network/container filesystems can swallow inotify events entirely. This
+/// spawns a dedicated thread that forwards `SIGHUP` onto a channel, then has
+/// the watcher thread `select!` over that channel alongside the usual notify
+/// events, so a reload can be forced deterministically with `kill -HUP <pid>`.
+pub fn watch_changes_with_signal<C>(
     cfg: Arc<Mutex<C>>,
     mode: String,
     path: String,
@@ -59,58 +396,77 @@ where
     C: Debug + Send + 'static,
 {
     let mode = RecursiveMode::from_str(&mode)?.convert();
-    std::thread::spawn(move || loop {
-        let (tx, rx) = unbounded();
 
-        let mut watcher: RecommendedWatcher =
-            RecommendedWatcher::new(tx).expect("Cannot create watcher");
-        watcher
-            .watch(std::path::Path::new(&path), mode)
-            .expect("Cannot listen filesystem");
-
-        match rx.recv().map_err(|e| {
-            anyhow::anyhow!("Receiving events from watcher error: {:?}", e)
-        }) {
-            Ok(event) => match event {
-                Ok(e) => {
-                    tracing::trace!("Captured event: {:#?}", e);
-                    // Modify: DataChange::Any gets triggered everytime you open
-                    // the config file and perform empty save. It was kind of
-                    // annoying so I had to use AccessKind:Close so that I can
-                    // track if someone opened and closed the config file.
-                    // However it does not track the changes the way Modify does
-                    // so I go back to the modify.
-                    // If it causes any undefined behaviours, consider changing
-                    // back this to Accesskind again.
-                    // Full code should be something like this:
-                    // ```rust
-                    // if e.kind == notify::EventKind::Access(AccessKind::Close(
-                    //     AccessMode::Write,
-                    // )) {...}
-                    // ```
-                    if e.kind.is_modify() ||
-                        e.kind.is_create() ||
-                        e.kind.is_remove()
-                    {
-                        tracing::trace!("Event kind: {:?}", e.kind);
-                        match f() {
-                            Ok(new_config) => {
-                                tracing::trace!(
-                                    "New config: {:?} - Old config: {:?}",
-                                    &new_config,
-                                    cfg,
-                                );
-                                *cfg.lock().unwrap() = new_config
-                            }
-                            Err(e) => {
-                                tracing::error!("Cannot reload config: {:?}", e)
-                            }
-                        }
+    let mut signals = Signals::new([SIGHUP])?;
+    let (sig_tx, sig_rx) = unbounded();
+    std::thread::spawn(move || {
+        for _ in signals.forever() {
+            tracing::trace!("Received SIGHUP, forcing config reload");
+            if sig_tx.send(()).is_err() {
+                break;
+            }
+        }
+    });
+
+    let (watcher, rx) =
+        build_watcher(&WatcherBackend::Recommended, mode, &[path])?;
+
+    std::thread::spawn(move || {
+        // Keep the watcher alive for the life of the thread; dropping it
+        // cancels the filesystem subscription.
+        let _watcher = watcher;
+        loop {
+            let trigger = select! {
+                recv(rx) -> event => match event {
+                    Ok(Ok(e)) => {
+                        tracing::trace!("Captured event: {:#?}", e);
+                        Trigger::Fs(is_reload_event(&e.kind))
+                    }
+                    Ok(Err(e)) => {
+                        tracing::error!("Event error: {:?}", e);
+                        Trigger::Fs(false)
+                    }
+                    Err(e) => {
+                        tracing::error!(
+                            "Receiving events from watcher error: {:?}",
+                            e
+                        );
+                        return;
                     }
+                },
+                recv(sig_rx) -> _ => Trigger::Sig,
+            };
+
+            let should_reload = match trigger {
+                // Coalesce a burst of filesystem events into a single
+                // reload; bail out if the watcher disconnected meanwhile.
+                Trigger::Fs(true) => {
+                    if !drain_burst(&rx) {
+                        return;
+                    }
+                    true
+                }
+                Trigger::Fs(false) => false,
+                // A SIGHUP is a single explicit operator action, so reload
+                // right away instead of waiting out the debounce window.
+                Trigger::Sig => true,
+            };
+
+            if !should_reload {
+                continue;
+            }
+
+            match f() {
+                Ok(new_config) => {
+                    tracing::trace!(
+                        "New config: {:?} - Old config: {:?}",
+                        &new_config,
+                        cfg,
+                    );
+                    *cfg.lock().unwrap() = new_config
                 }
-                Err(e) => tracing::error!("Event error: {:?}", e),
-            },
-            Err(e) => tracing::error!("Error: {:?}", e),
+                Err(e) => tracing::error!("Cannot reload config: {:?}", e),
+            }
         }
     });
     Ok(())
@@ -135,8 +491,11 @@ mod test {
     }
 
     impl Config {
-        fn load() -> Result<Config> {
-            let f = BufReader::new(File::open("./config.yaml")?);
+        /// Shared fixture loader; the `load_*` wrappers below only exist
+        /// because `watch_changes*`'s `f` parameter is a bare
+        /// `fn() -> Result<C>` and can't close over a path.
+        fn load_from(path: &str) -> Result<Config> {
+            let f = BufReader::new(File::open(path)?);
             serde_yaml::from_reader(f).map_err(|e| {
                 anyhow::anyhow!(
                     "Cannot parse the config file: {}",
@@ -144,6 +503,30 @@ mod test {
                 )
             })
         }
+
+        fn load() -> Result<Config> {
+            Config::load_from("./config.yaml")
+        }
+
+        fn load_swap() -> Result<Config> {
+            Config::load_from("./config_swap.yaml")
+        }
+
+        fn load_signal() -> Result<Config> {
+            Config::load_from("./config_signal.yaml")
+        }
+
+        fn load_poll() -> Result<Config> {
+            Config::load_from("./config_poll.yaml")
+        }
+
+        fn load_pattern() -> Result<Config> {
+            Config::load_from("./pattern_dir/config.yaml")
+        }
+
+        fn load_burst() -> Result<Config> {
+            Config::load_from("./config_burst.yaml")
+        }
     }
     #[test]
     fn reload_config() {
@@ -156,12 +539,47 @@ val: "SomeData"
         let clone = Arc::clone(&cfg);
         assert_eq!(clone.lock().unwrap().test, "SomeData");
         assert_eq!(clone.lock().unwrap().val, "SomeData");
+        let reload_events = watch_changes(
+            clone,
+            "recursive".to_string(),
+            "recommended".to_string(),
+            vec!["config.yaml".to_string()],
+            vec![],
+            Config::load,
+        )
+        .unwrap();
+        std::thread::sleep(std::time::Duration::from_secs(2));
+        let data = r#"---
+test: "OtherData"
+val: "OtherData"
+        "#;
+        std::fs::write("config.yaml", data).unwrap();
+        std::thread::sleep(std::time::Duration::from_secs(2));
+        assert_eq!(cfg.lock().unwrap().test, "OtherData");
+        assert_eq!(cfg.lock().unwrap().test, "OtherData");
+        assert!(matches!(
+            reload_events.try_recv(),
+            Ok(ReloadEvent::Reloaded)
+        ));
+    }
+
+    #[test]
+    fn reload_config_swap() {
+        let data = r#"---
+test: "SomeData"
+val: "SomeData"
+        "#;
+        std::fs::write("config_swap.yaml", data).unwrap();
+        let cfg = Arc::new(ArcSwap::from_pointee(Config::load_swap().unwrap()));
+        let clone = Arc::clone(&cfg);
+        assert_eq!(clone.load().test, "SomeData");
+        assert_eq!(clone.load().val, "SomeData");
         {
-            let r = watch_changes(
+            let r = watch_changes_swap(
                 clone,
                 "recursive".to_string(),
-                "config.yaml".to_string(),
-                Config::load,
+                "config_swap.yaml".to_string(),
+                Config::load_swap,
             );
             assert_eq!(true, r.is_ok());
         }
@@ -170,9 +588,161 @@ val: "SomeData"
 test: "OtherData"
 val: "OtherData"
         "#;
-        std::fs::write("config.yaml", data).unwrap();
+        std::fs::write("config_swap.yaml", data).unwrap();
+        std::thread::sleep(std::time::Duration::from_secs(2));
+        assert_eq!(cfg.load().test, "OtherData");
+        assert_eq!(cfg.load().val, "OtherData");
+    }
+
+    #[test]
+    fn reload_config_on_sighup() {
+        let data = r#"---
+test: "SomeData"
+val: "SomeData"
+        "#;
+        std::fs::write("config_signal.yaml", data).unwrap();
+
+        // Seed the config with a value that doesn't match what's on disk, so
+        // a later reload can only be explained by the SIGHUP trigger and not
+        // by the initial load.
+        let cfg = Arc::new(Mutex::new(Config {
+            test: "Unset".to_string(),
+            val:  "Unset".to_string(),
+        }));
+
+        let r = watch_changes_with_signal(
+            Arc::clone(&cfg),
+            "recursive".to_string(),
+            "config_signal.yaml".to_string(),
+            Config::load_signal,
+        );
+        assert_eq!(true, r.is_ok());
+
+        std::thread::sleep(std::time::Duration::from_millis(500));
+        unsafe {
+            libc::raise(libc::SIGHUP);
+        }
         std::thread::sleep(std::time::Duration::from_secs(2));
+
+        assert_eq!(cfg.lock().unwrap().test, "SomeData");
+        assert_eq!(cfg.lock().unwrap().val, "SomeData");
+    }
+
+    #[test]
+    fn reload_config_poll_backend() {
+        let data = r#"---
+test: "SomeData"
+val: "SomeData"
+        "#;
+        std::fs::write("config_poll.yaml", data).unwrap();
+        let cfg = Arc::new(Mutex::new(Config::load_poll().unwrap()));
+        let reload_events = watch_changes(
+            Arc::clone(&cfg),
+            "recursive".to_string(),
+            "poll:50".to_string(),
+            vec!["config_poll.yaml".to_string()],
+            vec![],
+            Config::load_poll,
+        )
+        .unwrap();
+        std::thread::sleep(std::time::Duration::from_millis(300));
+        let data = r#"---
+test: "OtherData"
+val: "OtherData"
+        "#;
+        std::fs::write("config_poll.yaml", data).unwrap();
+
+        let event = reload_events
+            .recv_timeout(std::time::Duration::from_secs(2))
+            .unwrap();
+        assert!(matches!(event, ReloadEvent::Reloaded));
         assert_eq!(cfg.lock().unwrap().test, "OtherData");
+    }
+
+    #[test]
+    fn reload_config_pattern_filter() {
+        std::fs::create_dir_all("pattern_dir").unwrap();
+        let data = r#"---
+test: "SomeData"
+val: "SomeData"
+        "#;
+        std::fs::write("pattern_dir/config.yaml", data).unwrap();
+        let cfg = Arc::new(Mutex::new(Config::load_pattern().unwrap()));
+        let reload_events = watch_changes(
+            Arc::clone(&cfg),
+            "recursive".to_string(),
+            "recommended".to_string(),
+            vec!["pattern_dir".to_string()],
+            vec!["*.yaml".to_string()],
+            Config::load_pattern,
+        )
+        .unwrap();
+
+        // A change to a file that doesn't match the pattern must not
+        // trigger a reload.
+        std::fs::write("pattern_dir/ignored.txt", "noise").unwrap();
+        assert!(reload_events
+            .recv_timeout(std::time::Duration::from_millis(500))
+            .is_err());
+
+        // A change to a file that does match the pattern does.
+        let data = r#"---
+test: "OtherData"
+val: "OtherData"
+        "#;
+        std::fs::write("pattern_dir/config.yaml", data).unwrap();
+        let event = reload_events
+            .recv_timeout(std::time::Duration::from_secs(2))
+            .unwrap();
+        assert!(matches!(event, ReloadEvent::Reloaded));
         assert_eq!(cfg.lock().unwrap().test, "OtherData");
     }
+
+    #[test]
+    fn reload_config_debounces_burst_and_reports_rejection() {
+        let data = r#"---
+test: "SomeData"
+val: "SomeData"
+        "#;
+        std::fs::write("config_burst.yaml", data).unwrap();
+        let cfg = Arc::new(Mutex::new(Config::load_burst().unwrap()));
+        let reload_events = watch_changes(
+            Arc::clone(&cfg),
+            "recursive".to_string(),
+            "recommended".to_string(),
+            vec!["config_burst.yaml".to_string()],
+            vec![],
+            Config::load_burst,
+        )
+        .unwrap();
+
+        // A burst of rapid writes inside the debounce window should
+        // coalesce into a single reload instead of one per write.
+        for i in 0..5 {
+            std::fs::write(
+                "config_burst.yaml",
+                format!(
+                    "---\ntest: \"Burst{}\"\nval: \"Burst{}\"\n",
+                    i, i
+                ),
+            )
+            .unwrap();
+        }
+        let event = reload_events
+            .recv_timeout(std::time::Duration::from_secs(2))
+            .unwrap();
+        assert!(matches!(event, ReloadEvent::Reloaded));
+        assert!(reload_events
+            .recv_timeout(std::time::Duration::from_millis(500))
+            .is_err());
+
+        // A file that fails to parse is rejected; the last known-good
+        // config stays in effect.
+        std::fs::write("config_burst.yaml", "not: [valid").unwrap();
+        let event = reload_events
+            .recv_timeout(std::time::Duration::from_secs(2))
+            .unwrap();
+        assert!(matches!(event, ReloadEvent::Rejected(_)));
+        assert_eq!(cfg.lock().unwrap().test, "Burst4");
+    }
 }